@@ -10,9 +10,130 @@
 
 use crate::prelude::*;
 
+// `abs`/`signum` for public machine integers: signed types forward to the
+// stdlib, unsigned types are trivially `abs(x) == x` and never negative.
+macro_rules! impl_public_abs_signum {
+    (signed, $t:ty) => {
+        /// `|self|`
+        fn abs(self) -> Self {
+            <$t>::abs(self)
+        }
+        /// `-1`, `0`, or `1` depending on the sign of `self`.
+        fn signum(self) -> Self {
+            <$t>::signum(self)
+        }
+    };
+    (unsigned, $t:ty) => {
+        /// `|self|`. Identity, since unsigned integers are never negative.
+        fn abs(self) -> Self {
+            self
+        }
+        /// `0` if `self` is zero, `1` otherwise.
+        fn signum(self) -> Self {
+            if self == 0 {
+                0
+            } else {
+                1
+            }
+        }
+    };
+}
+
+// `abs`/`signum` for secret machine integers: signed types use a
+// branchless, mask-based absolute value/sign so the secret sign bit never
+// drives control flow. Unsigned types are trivially `abs(x) == x`.
+macro_rules! impl_secret_abs_signum {
+    (signed, $t:ident, $base:ty, $bits:literal) => {
+        /// `|self|`.
+        ///
+        /// Constant time: the standard branchless absolute value. The sign
+        /// mask is an arithmetic right-shift by `NUM_BITS - 1` (all-one for
+        /// negative values, all-zero otherwise), and `(self ^ mask) -
+        /// mask` negates exactly when the mask is set.
+        fn abs(self) -> Self {
+            let mask = self >> ($bits - 1);
+            (self ^ mask).wrap_sub(mask)
+        }
+        /// `-1`, `0`, or `1` depending on the sign of `self`.
+        ///
+        /// Constant time: built from the same sign mask as `abs`, plus a
+        /// mask-based equal-to-zero check, so no secret value influences
+        /// control flow. A negative `self` has `neg_mask` equal to `-1`
+        /// already, so it is used directly rather than selecting `ONE`.
+        fn signum(self) -> Self {
+            let neg_mask = self >> ($bits - 1);
+            let nonzero_mask = self.not_equal_bm(Self::ZERO);
+            let pos_mask = nonzero_mask & !neg_mask;
+            (Self::ONE & pos_mask) | neg_mask
+        }
+    };
+    (unsigned, $t:ident, $base:ty, $bits:literal) => {
+        /// `|self|`. Identity, since unsigned integers are never negative.
+        fn abs(self) -> Self {
+            self
+        }
+        /// `0` if `self` is zero, `1` otherwise.
+        fn signum(self) -> Self {
+            self.not_equal_bm(Self::ZERO) & Self::ONE
+        }
+    };
+}
+
+// `overflowing_add`/`overflowing_sub` for secret machine integers. Carry
+// detection differs by signedness, so (unlike `overflowing_mul`, which is
+// shared) each sign gets its own mask formula; neither declassifies the
+// operands, only the resulting one-bit flag.
+macro_rules! impl_secret_overflowing_addsub {
+    (unsigned, $t:ident, $base:ty, $bits:literal) => {
+        /// `self + rhs` and whether the addition overflowed.
+        ///
+        /// A wrapped sum smaller than either operand signals unsigned
+        /// overflow (the carry out of the top bit).
+        fn overflowing_add(self, rhs: Self) -> (Self, bool) {
+            let result = self.wrap_add(rhs);
+            let carry_mask = result.less_than_bm(self);
+            let carry = <$t>::declassify(carry_mask) != <$base>::default();
+            (result, carry)
+        }
+        /// `self - rhs` and whether the subtraction underflowed.
+        fn overflowing_sub(self, rhs: Self) -> (Self, bool) {
+            let result = self.wrap_sub(rhs);
+            let borrow_mask = self.less_than_bm(rhs);
+            let borrow = <$t>::declassify(borrow_mask) != <$base>::default();
+            (result, borrow)
+        }
+    };
+    (signed, $t:ident, $base:ty, $bits:literal) => {
+        /// `self + rhs` and whether the addition overflowed.
+        ///
+        /// Two's-complement overflow: the operands share a sign and the
+        /// result's sign differs from theirs, i.e. the top bit of
+        /// `!(self ^ rhs) & (self ^ result)` is set.
+        fn overflowing_add(self, rhs: Self) -> (Self, bool) {
+            let result = self.wrap_add(rhs);
+            let overflow_bits = !(self ^ rhs) & (self ^ result);
+            let overflow_mask = overflow_bits >> ($bits - 1);
+            let overflow = <$t>::declassify(overflow_mask) != <$base>::default();
+            (result, overflow)
+        }
+        /// `self - rhs` and whether the subtraction overflowed.
+        ///
+        /// Two's-complement overflow: the operands differ in sign and the
+        /// result's sign differs from `self`'s, i.e. the top bit of
+        /// `(self ^ rhs) & (self ^ result)` is set.
+        fn overflowing_sub(self, rhs: Self) -> (Self, bool) {
+            let result = self.wrap_sub(rhs);
+            let overflow_bits = (self ^ rhs) & (self ^ result);
+            let overflow_mask = overflow_bits >> ($bits - 1);
+            let overflow = <$t>::declassify(overflow_mask) != <$base>::default();
+            (result, overflow)
+        }
+    };
+}
+
 // Macro to implement the Numeric trait for built-in machine integers.
 macro_rules! implement_public_mi {
-    ($t:ty,$bits:literal) => {
+    ($t:ty,$bits:literal,$sign:ident) => {
         impl Numeric for $t {}
         impl IntegerRename for $t {
             const NUM_BITS: u32 = $bits;
@@ -44,13 +165,56 @@ macro_rules! implement_public_mi {
                 self.wrapping_div(rhs)
             }
 
+            /// `self + rhs`, or `None` on overflow.
+            fn checked_add(self, rhs: Self) -> Option<Self> {
+                self.checked_add(rhs)
+            }
+            /// `self - rhs`, or `None` on underflow.
+            fn checked_sub(self, rhs: Self) -> Option<Self> {
+                self.checked_sub(rhs)
+            }
+            /// `self * rhs`, or `None` on overflow.
+            fn checked_mul(self, rhs: Self) -> Option<Self> {
+                self.checked_mul(rhs)
+            }
+            /// `self / rhs`, or `None` on division by zero.
+            fn checked_div(self, rhs: Self) -> Option<Self> {
+                self.checked_div(rhs)
+            }
+
+            /// `self + rhs` and whether the addition overflowed.
+            fn overflowing_add(self, rhs: Self) -> (Self, bool) {
+                self.overflowing_add(rhs)
+            }
+            /// `self - rhs` and whether the subtraction underflowed.
+            fn overflowing_sub(self, rhs: Self) -> (Self, bool) {
+                self.overflowing_sub(rhs)
+            }
+            /// `self * rhs` and whether the multiplication overflowed.
+            fn overflowing_mul(self, rhs: Self) -> (Self, bool) {
+                self.overflowing_mul(rhs)
+            }
+
             /// `self ^ exp` where `exp` is a `u32`.
             fn pow(self, exp: u32) -> Self {
                 self.pow(exp)
             }
             /// `self ^ exp` where `exp` is a `Self`.
-            fn pow_self(self, _exp: Self) -> Self {
-                unimplemented!();
+            ///
+            /// Variable time square-and-multiply; public integers have no
+            /// constant-time requirement.
+            fn pow_self(self, exp: Self) -> Self {
+                let mut result = Self::ONE;
+                let mut base = self;
+                let mut e = exp;
+                while e > Self::ZERO {
+                    if e & Self::ONE == Self::ONE {
+                        result = result * base;
+                    }
+                    base = base * base;
+                    e = e >> 1;
+                }
+                result
             }
             /// (self - rhs) % n.
             fn sub_mod(self, rhs: Self, n: Self) -> Self {
@@ -65,8 +229,21 @@ macro_rules! implement_public_mi {
                 (self * rhs) % n
             }
             /// `(self ^ exp) % n`
-            fn pow_mod(self, _exp: Self, _n: Self) -> Self {
-                unimplemented!();
+            ///
+            /// Variable time square-and-multiply; public integers have no
+            /// constant-time requirement.
+            fn pow_mod(self, exp: Self, n: Self) -> Self {
+                let mut result = Self::ONE % n;
+                let mut base = self % n;
+                let mut e = exp;
+                while e > Self::ZERO {
+                    if e & Self::ONE == Self::ONE {
+                        result = (result * base) % n;
+                    }
+                    base = (base * base) % n;
+                    e = e >> 1;
+                }
+                result
             }
             /// Division.
             fn div(self, rhs: Self) -> Self {
@@ -76,14 +253,72 @@ macro_rules! implement_public_mi {
             fn rem(self, n: Self) -> Self {
                 self % n
             }
-            /// Invert self modulo n.
-            fn inv(self, _n: Self) -> Self {
-                unimplemented!();
+            /// Euclidean division: the quotient for which
+            /// `div_euclid * n + rem_euclid == self` with a non-negative
+            /// `rem_euclid`. It rounds toward negative infinity for positive
+            /// `n` and toward positive infinity for negative `n`.
+            /// Coincides with `div` for unsigned types.
+            fn div_euclid(self, n: Self) -> Self {
+                self.div_euclid(n)
             }
-            /// `|self|`
-            fn abs(self) -> Self {
-                unimplemented!();
+            /// The non-negative remainder of Euclidean division, always in
+            /// `[0, n.abs())`. Coincides with `rem` for unsigned types.
+            fn rem_euclid(self, n: Self) -> Self {
+                self.rem_euclid(n)
             }
+            /// Invert self modulo n.
+            ///
+            /// **Note:** variable time. Uses the extended binary GCD, which
+            /// branches on the values of `self` and `n`, so only use this for
+            /// public integers. Unlike the secret types' Fermat-based `inv`,
+            /// this supports moduli that are not prime, but `n` must be odd:
+            /// the halving step adds `n` to make an odd intermediate even,
+            /// so an even `n` gives a wrong result.
+            ///
+            /// All intermediates (`x1`, `x2`) are kept in `[0, n)`: halving
+            /// an odd intermediate uses `overflowing_add` to recover the bit
+            /// shifted off the top rather than forming `x + n` directly
+            /// (which can exceed the type's range for large `n`), and
+            /// subtracting the larger from the smaller is rewritten as
+            /// `n - (other - self)` for the same reason.
+            fn inv(self, n: Self) -> Self {
+                let halve_mod = |x: Self, n: Self| -> Self {
+                    if x % 2 == 0 {
+                        x / 2
+                    } else {
+                        let (sum, carry) = x.overflowing_add(n);
+                        let carry_bit = if carry { 1 << ($bits - 1) } else { 0 };
+                        (sum >> 1) ^ carry_bit
+                    }
+                };
+                let mut u = self;
+                let mut v = n;
+                let mut x1: Self = 1;
+                let mut x2: Self = 0;
+                while u != 1 && v != 1 {
+                    while u % 2 == 0 {
+                        u = u / 2;
+                        x1 = halve_mod(x1, n);
+                    }
+                    while v % 2 == 0 {
+                        v = v / 2;
+                        x2 = halve_mod(x2, n);
+                    }
+                    if u >= v {
+                        u = u - v;
+                        x1 = if x1 >= x2 { x1 - x2 } else { n - (x2 - x1) };
+                    } else {
+                        v = v - u;
+                        x2 = if x2 >= x1 { x2 - x1 } else { n - (x1 - x2) };
+                    }
+                }
+                if u == 1 {
+                    x1 % n
+                } else {
+                    x2 % n
+                }
+            }
+            impl_public_abs_signum!($sign, $t);
 
             // Comparison functions returning bool.
             fn equal(self, other: Self) -> bool {
@@ -149,23 +384,23 @@ macro_rules! implement_public_mi {
     };
 }
 
-implement_public_mi!(u8, 8);
-implement_public_mi!(u16, 16);
-implement_public_mi!(u32, 32);
-implement_public_mi!(u64, 64);
-implement_public_mi!(u128, 128);
+implement_public_mi!(u8, 8, unsigned);
+implement_public_mi!(u16, 16, unsigned);
+implement_public_mi!(u32, 32, unsigned);
+implement_public_mi!(u64, 64, unsigned);
+implement_public_mi!(u128, 128, unsigned);
 
-implement_public_mi!(i8, 8);
-implement_public_mi!(i16, 16);
-implement_public_mi!(i32, 32);
-implement_public_mi!(i64, 64);
-implement_public_mi!(i128, 128);
+implement_public_mi!(i8, 8, signed);
+implement_public_mi!(i16, 16, signed);
+implement_public_mi!(i32, 32, signed);
+implement_public_mi!(i64, 64, signed);
+implement_public_mi!(i128, 128, signed);
 
 
 // FIXME: This is currently NOT constant time! Implement the underlying algorithms in secret integer.
 // Macro to implement the Numeric trait for secret machine integers.
 macro_rules! implement_secret_mi {
-    ($t:ident,$base:ty,$bits:literal) => {
+    ($t:ident,$base:ty,$bits:literal,$sign:ident) => {
         impl Numeric for $t {}
         impl IntegerRename for $t {
             const NUM_BITS: u32 = $bits;
@@ -197,6 +432,47 @@ macro_rules! implement_secret_mi {
                 unimplemented!();
             }
 
+            /// `self + rhs`, or `None` on overflow.
+            fn checked_add(self, rhs: Self) -> Option<Self> {
+                let s = <$t>::declassify(self);
+                let o = <$t>::declassify(rhs);
+                s.checked_add(o).map(Self::from)
+            }
+            /// `self - rhs`, or `None` on underflow.
+            fn checked_sub(self, rhs: Self) -> Option<Self> {
+                let s = <$t>::declassify(self);
+                let o = <$t>::declassify(rhs);
+                s.checked_sub(o).map(Self::from)
+            }
+            /// `self * rhs`, or `None` on overflow.
+            fn checked_mul(self, rhs: Self) -> Option<Self> {
+                let s = <$t>::declassify(self);
+                let o = <$t>::declassify(rhs);
+                s.checked_mul(o).map(Self::from)
+            }
+            /// `self / rhs`, or `None` on division by zero.
+            fn checked_div(self, rhs: Self) -> Option<Self> {
+                let s = <$t>::declassify(self);
+                let o = <$t>::declassify(rhs);
+                s.checked_div(o).map(Self::from)
+            }
+
+            impl_secret_overflowing_addsub!($sign, $t, $base, $bits);
+            /// `self * rhs` and whether the multiplication overflowed.
+            ///
+            /// **Note:** unlike `overflowing_add`/`overflowing_sub`, this
+            /// declassifies both operands (not just the resulting flag) to
+            /// forward to the base type's `overflowing_mul` — a
+            /// constant-time multiply-with-overflow over `NUM_BITS` would
+            /// need a double-width accumulator this trait doesn't have. Only
+            /// use this when `self`/`rhs` need not stay secret.
+            fn overflowing_mul(self, rhs: Self) -> (Self, bool) {
+                let s = <$t>::declassify(self);
+                let o = <$t>::declassify(rhs);
+                let (wrapped, overflow) = s.overflowing_mul(o);
+                (Self::from(wrapped), overflow)
+            }
+
             /// `self ^ exp` where `exp` is a `u32`.
             /// **Note:** the exponent `exp` MUST not be secret.
             fn pow(self, exp: u32) -> Self {
@@ -212,33 +488,103 @@ macro_rules! implement_secret_mi {
             }
             /// `self ^ exp` where `exp` is a `Self`.
             /// Here both, base and exponent, are secret.
-            fn pow_self(self, _exp: Self) -> Self {
-                unimplemented!();
+            ///
+            /// Constant time: Montgomery ladder over `Self::NUM_BITS` exponent
+            /// bits, conditionally swapping the ladder registers with a mask
+            /// rather than branching on a secret bit.
+            fn pow_self(self, exp: Self) -> Self {
+                let mut r0 = Self::ONE;
+                let mut r1 = self;
+                for i in (0..Self::NUM_BITS).rev() {
+                    let bit = (exp >> i) & Self::ONE;
+                    let swap = bit.equal_bm(Self::ONE);
+                    let t0 = (r0 & !swap) | (r1 & swap);
+                    let t1 = (r1 & !swap) | (r0 & swap);
+                    r0 = t0;
+                    r1 = t1;
+                    r1 = r0.wrap_mul(r1);
+                    r0 = r0.wrap_mul(r0);
+                    let t0 = (r0 & !swap) | (r1 & swap);
+                    let t1 = (r1 & !swap) | (r0 & swap);
+                    r0 = t0;
+                    r1 = t1;
+                }
+                r0
             }
             /// (self - rhs) % n.
+            ///
+            /// Constant time: `self` and `rhs` are assumed already reduced mod `n`,
+            /// so the result only ever needs a single conditional correction.
             fn sub_mod(self, rhs: Self, n: Self) -> Self {
-                let s = <$t>::declassify(self);
-                let o = <$t>::declassify(rhs);
-                let n = <$t>::declassify(n);
-                Self::from((s - o) % n)
+                let d = self.wrap_sub(rhs);
+                let mask = self.less_than_bm(rhs);
+                d.wrap_add(n & mask)
             }
             /// `(self + rhs) % n`
+            ///
+            /// Constant time: `self` and `rhs` are assumed already reduced mod
+            /// `n`. The conditional subtraction fires either when the
+            /// wrapping add carried out of the word (`t < self`) or when it
+            /// landed in `[n, 2n)` without carrying (`t >= n`).
             fn add_mod(self, rhs: Self, n: Self) -> Self {
-                let s = <$t>::declassify(self);
-                let o = <$t>::declassify(rhs);
-                let n = <$t>::declassify(n);
-                Self::from((s + o) % n)
+                let t = self.wrap_add(rhs);
+                let mask = t.less_than_bm(self) | t.greater_than_or_qual_bm(n);
+                t.wrap_sub(n & mask)
             }
             /// `(self * rhs) % n`
-            fn mul_mod(self, rhs: Self, n: Self) -> Self{
-                let s = <$t>::declassify(self);
-                let o = <$t>::declassify(rhs);
-                let n = <$t>::declassify(n);
-                Self::from((s * o) % n)
+            ///
+            /// Constant time: `self` and `rhs` are assumed already reduced mod
+            /// `n` (reducing them here would require the leaky, declassifying
+            /// `rem`). Runs a fixed `NUM_BITS` shift-add-reduce loop over
+            /// `rhs` regardless of its value, so no secret operand influences
+            /// control flow.
+            fn mul_mod(self, rhs: Self, n: Self) -> Self {
+                let mut result = Self::ZERO;
+                let mut a = self;
+                let mut b = rhs;
+                for _ in 0..Self::NUM_BITS {
+                    let bit = b & Self::ONE;
+                    let take = bit.equal_bm(Self::ONE);
+                    result = result.add_mod(a & take, n);
+                    a = a.add_mod(a, n);
+                    b = b >> 1;
+                }
+                result
             }
             /// `(self ^ exp) % n`
-            fn pow_mod(self, _exp: Self, _n: Self) -> Self {
-                unimplemented!();
+            ///
+            /// Constant time: Montgomery ladder over `Self::NUM_BITS` exponent
+            /// bits, using the constant-time `mul_mod` for both the ladder's
+            /// multiply and square steps and a mask-based conditional swap
+            /// instead of branching on a secret bit. `self` is reduced mod
+            /// `n` with a bit-serial shift-and-conditional-subtract loop
+            /// (never the leaky, declassifying `rem`) to satisfy `mul_mod`'s
+            /// already-reduced precondition; `ONE` needs no such reduction
+            /// since `n` is assumed `> 1`.
+            fn pow_mod(self, exp: Self, n: Self) -> Self {
+                let mut r0 = Self::ONE;
+                let mut r1 = Self::ZERO;
+                for i in (0..Self::NUM_BITS).rev() {
+                    let bit = (self >> i) & Self::ONE;
+                    r1 = (r1 << 1) | bit;
+                    let mask = r1.greater_than_or_qual_bm(n);
+                    r1 = r1.wrap_sub(n & mask);
+                }
+                for i in (0..Self::NUM_BITS).rev() {
+                    let bit = (exp >> i) & Self::ONE;
+                    let swap = bit.equal_bm(Self::ONE);
+                    let t0 = (r0 & !swap) | (r1 & swap);
+                    let t1 = (r1 & !swap) | (r0 & swap);
+                    r0 = t0;
+                    r1 = t1;
+                    r1 = r0.mul_mod(r1, n);
+                    r0 = r0.mul_mod(r0, n);
+                    let t0 = (r0 & !swap) | (r1 & swap);
+                    let t1 = (r1 & !swap) | (r0 & swap);
+                    r0 = t0;
+                    r1 = t1;
+                }
+                r0
             }
             /// Division.
             fn div(self, rhs: Self) -> Self {
@@ -252,14 +598,33 @@ macro_rules! implement_secret_mi {
                 let n = <$t>::declassify(n);
                 Self::from(s % n)
             }
-            /// Invert self modulo n.
-            fn inv(self, _n: Self) -> Self {
-                unimplemented!();
+            /// Euclidean division: the quotient for which
+            /// `div_euclid * n + rem_euclid == self` with a non-negative
+            /// `rem_euclid`. It rounds toward negative infinity for positive
+            /// `n` and toward positive infinity for negative `n`.
+            /// Coincides with `div` for unsigned types.
+            fn div_euclid(self, n: Self) -> Self {
+                let s = <$t>::declassify(self);
+                let n = <$t>::declassify(n);
+                Self::from(s.div_euclid(n))
             }
-            /// `|self|`
-            fn abs(self) -> Self {
-                unimplemented!();
+            /// The non-negative remainder of Euclidean division, always in
+            /// `[0, n.abs())`. Coincides with `rem` for unsigned types.
+            fn rem_euclid(self, n: Self) -> Self {
+                let s = <$t>::declassify(self);
+                let n = <$t>::declassify(n);
+                Self::from(s.rem_euclid(n))
             }
+            /// Invert self modulo n.
+            ///
+            /// **Note:** assumes `n` is prime. Computed via Fermat's little
+            /// theorem (`self.pow_mod(n - TWO, n)`), so it inherits the
+            /// constant-time Montgomery ladder from `pow_mod`, and with it
+            /// the `add_mod`/`mul_mod` carry fix.
+            fn inv(self, n: Self) -> Self {
+                self.pow_mod(n.wrap_sub(Self::TWO), n)
+            }
+            impl_secret_abs_signum!($sign, $t, $base, $bits);
 
             // Comparison functions returning bool.
             fn equal(self, other: Self) -> bool {
@@ -311,15 +676,15 @@ macro_rules! implement_secret_mi {
     };
 }
 
-implement_secret_mi!(U8, u8, 8);
-implement_secret_mi!(U16, u16, 16);
-implement_secret_mi!(U32, u32, 32);
-implement_secret_mi!(U64, u64, 64);
-implement_secret_mi!(U128, u128, 128);
+implement_secret_mi!(U8, u8, 8, unsigned);
+implement_secret_mi!(U16, u16, 16, unsigned);
+implement_secret_mi!(U32, u32, 32, unsigned);
+implement_secret_mi!(U64, u64, 64, unsigned);
+implement_secret_mi!(U128, u128, 128, unsigned);
 
 // FIXME: requires code in secret integers for constant-time comparison
-implement_secret_mi!(I8, i8, 8);
-implement_secret_mi!(I16, i16, 16);
-implement_secret_mi!(I32, i32, 32);
-implement_secret_mi!(I64, i64, 64);
-implement_secret_mi!(I128, i128, 128);
+implement_secret_mi!(I8, i8, 8, signed);
+implement_secret_mi!(I16, i16, 16, signed);
+implement_secret_mi!(I32, i32, 32, signed);
+implement_secret_mi!(I64, i64, 64, signed);
+implement_secret_mi!(I128, i128, 128, signed);